@@ -1,5 +1,5 @@
 from pydian.dicts import get
-from pydian.lib.types import DROP
+from pydian.lib.types import DROP, KEEP
 from pydian.mapper import Mapper
 
-__all__ = ["DROP", "Mapper", "get"]
+__all__ = ["DROP", "KEEP", "Mapper", "get"]
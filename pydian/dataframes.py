@@ -1,6 +1,7 @@
 import ast
 import re
 from collections import defaultdict
+from dataclasses import dataclass
 from typing import Any, Iterable
 
 import polars as pl
@@ -106,6 +107,110 @@ def inner_join(
     return res if not res.is_empty() else Err("Empty dataframe")
 
 
+@dataclass(frozen=True)
+class DiffFrames:
+    added: pl.DataFrame
+    removed: pl.DataFrame
+    changed: pl.DataFrame
+
+
+def diff_frames(
+    first: pl.DataFrame,
+    second: pl.DataFrame,
+    on: str | list[str],
+    changed_col: str = "changed_cols",
+) -> DiffFrames | Err:
+    """
+    Compares two DataFrames by aligning rows on the `on` key columns:
+    - `added`: rows in `second` whose keys are not in `first`
+    - `removed`: rows in `first` whose keys are not in `second`
+    - `changed`: rows with matching keys where at least one shared column differs. Values
+        from `second` are included as `{col}_second` next to the values from `first`,
+        and `changed_col` lists the names of the columns that differ in that row
+    """
+    try:
+        _pre_merge_checks(first, second, on)
+    except KeyError as e:
+        return Err(f"Failed pre-merge checks: {str(e)}")
+    if isinstance(on, str):
+        on = [on]
+    if changed_col in first.columns or changed_col in second.columns:
+        return Err(f"Changed column {changed_col} is already in the columns!")
+
+    added = second.join(first, how="anti", on=on)
+    removed = first.join(second, how="anti", on=on)
+
+    shared_cols = [c for c in first.columns if c in second.columns and c not in on]
+    matched = first.select(on + shared_cols).join(
+        second.select(on + shared_cols), how="inner", on=on, suffix="_second"
+    )
+    if shared_cols:
+        is_diff = [pl.col(c).ne_missing(pl.col(f"{c}_second")) for c in shared_cols]
+        diff_names = [pl.when(d).then(pl.lit(c)) for d, c in zip(is_diff, shared_cols)]
+        changed = matched.filter(pl.any_horizontal(is_diff)).with_columns(
+            pl.concat_list(diff_names).list.drop_nulls().alias(changed_col)
+        )
+    else:
+        changed = matched.head(0).with_columns(
+            pl.lit(None, dtype=pl.List(pl.Utf8)).alias(changed_col)
+        )
+
+    return DiffFrames(added=added, removed=removed, changed=changed)
+
+
+def union(
+    frames: Iterable[pl.DataFrame], with_report: bool = False
+) -> pl.DataFrame | tuple[pl.DataFrame, dict[str, tuple[list[pl.DataType], pl.DataType]]] | Err:
+    """
+    Stacks DataFrames, aligning columns by name
+
+    Columns are upcast to a common supertype (e.g. `Int64` and `Float64` -> `Float64`),
+      and columns missing from a DataFrame are filled with `null`
+
+    If `with_report`, returns `(res, report)` where `report` maps each coerced column to
+      `(input dtypes, result dtype)`. Input dtypes are in frame order, skipping frames
+      without that column
+    """
+    frames = list(frames)
+    if not frames:
+        return Err("No DataFrames to union")
+    _check_assumptions(frames)
+    try:
+        res = pl.concat(frames, how="diagonal_relaxed")
+    except (
+        pl.exceptions.ComputeError,
+        pl.exceptions.InvalidOperationError,
+        pl.exceptions.SchemaError,
+    ) as e:
+        return Err(f"Error when taking union: {str(e)}")
+    if with_report:
+        report = {}
+        for c, dtype in res.schema.items():
+            in_dtypes = [f.schema[c] for f in frames if c in f.columns]
+            if any(d != dtype for d in in_dtypes):
+                report[c] = (in_dtypes, dtype)
+        return res, report
+    return res
+
+
+def duplicates(
+    source: pl.DataFrame, on: str | list[str], count_col: str = "count"
+) -> pl.DataFrame | Err:
+    """
+    Returns the rows where the `on` key columns appear more than once (order is kept),
+      with an added `count_col` column for the number of rows sharing that key
+    """
+    _check_assumptions(source)
+    if isinstance(on, str):
+        on = [on]
+    if missing := [c for c in on if c not in source.columns]:
+        return Err(f"Proposed keys {missing} are not in the columns!")
+    if count_col in source.columns:
+        return Err(f"Count column {count_col} is already in the columns!")
+    res = source.with_columns(pl.len().over(on).alias(count_col))
+    return res.filter(pl.col(count_col) > 1)
+
+
 # def insert(
 #     into: pl.DataFrame,
 #     rows=pl.DataFrame | list[dict[str, Any]],
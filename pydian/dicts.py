@@ -1,19 +1,47 @@
+import json
+import re
 import traceback
-from typing import Any, Callable, Iterable, Sequence
+from copy import deepcopy
+from enum import Enum
+from typing import Any, Callable, Iterable, Iterator, Sequence, TypeAlias
+
+from jmespath.parser import ParsedResult
 
 from .globs import SharedMapperState, _Global_Mapper_State_Dict
-from .lib.types import DROP, KEEP, ApplyFunc, ConditionalCheck
-from .lib.util import default_dsl, encode_stack_trace, flatten_list
+from .lib.types import DROP, KEEP, ApplyFunc, ConditionalCheck, ListMerge, NoneMerge
+from .lib.util import (
+    compile_key,
+    default_dsl,
+    encode_stack_trace,
+    find_recursive_descent,
+    flatten_list,
+    quote_key,
+    rewrite_tuples,
+)
+
+# Matches (in-order): a quoted identifier, a bracketed index, or a plain key
+REGEX_KEYPATH_TOKEN = re.compile(r'"((?:[^"\\]|\\.)*)"|\[([^\]]*)\]|([^.\[\]"]+)')
+
+
+class _Token(Enum):
+    """
+    _Keypath tokens that aren't keys, e.g. an unquoted `*` (a quoted `"*"` is a regular key)
+    """
+
+    WILDCARD = "*"
+
+
+_Keypath: TypeAlias = tuple[str | int | _Token, ...]
 
 
 def get(
     source: dict[str, Any] | list[Any],
-    key: str,
+    key: str | ParsedResult,
     default: Any = None,
     apply: ApplyFunc | Iterable[ApplyFunc] | None = None,
     only_if: ConditionalCheck | None = None,
     drop_level: DROP | None = None,
-    flatten: bool = False,
+    flatten: bool | int = False,
     strict: bool | None = None,
 ) -> Any:
     """
@@ -26,32 +54,37 @@ def get(
      - Iterate through a list using `[*]`
      - Get multiple items using `(firstKey,secondKey)` syntax (outputs as a tuple)
        The keys within the tuple can also be chained with `.`
+     - Search at any depth using `..`, e.g. `..id` gets all `id` values (outputs as a list)
+     - Double-quote keys containing `.` or `[]`, e.g. `extension."us.core.race".url`
+     - Pass a key from `compile_key` to skip parsing when reusing it (`..` isn't supported)
 
     Optional param notes:
     - `default`: Return value if `key` results in a `None` (before other params apply)
     - `apply`: Use to safely chain operations on a successful get
     - `only_if`: Use to conditionally decide if the result should be kept + `apply`-ed.
     - `drop_level`: Use to specify conditional dropping if get results in None.
-    - `flatten`: Use to flatten the final result (e.g. nested lists)
+    - `flatten`: Use to flatten the final result (e.g. nested lists). Pass an int to limit depth
     - `strict`: Use to throw `ValueError` instead of returning `None` (also available at `Mapper`-level)
     """
     # Grab context from `Mapper` classes (if relevant)
     mapper_state = _get_global_mapper_config()
     # For `strict`, prefer Mapper setting or take local setting
     strict = (mapper_state.strict if mapper_state else None) or strict
+    key_str = key.expression if isinstance(key, ParsedResult) else key
 
     if source:
         res = _nested_get(source, key, default)
-        _enforce_strict(res, strict, key, source)
+        _enforce_strict(res, strict, key_str, source)
     else:
         res = default
 
     if flatten and isinstance(res, list):
-        res = flatten_list(res)
+        # NOTE: `bool` is a subclass of `int`, so check for `True` explicitly
+        res = flatten_list(res, max_depth=None if flatten is True else flatten)
 
     if res is not None and only_if:
         res = res if only_if(res) else None
-        _enforce_strict(res, strict, key, source)
+        _enforce_strict(res, strict, key_str, source)
 
     if res is not None and apply:
         if not isinstance(apply, Iterable):
@@ -59,9 +92,11 @@ def get(
         for fn in apply:
             try:
                 res = fn(res)
-                _enforce_strict(res, strict, key, source)
+                _enforce_strict(res, strict, key_str, source)
             except Exception as e:
-                raise RuntimeError(f"`apply` call {fn} failed for value: {res} at key: {key}, {e}")
+                raise RuntimeError(
+                    f"`apply` call {fn} failed for value: {res} at key: {key_str}, {e}"
+                )
             if res is None:
                 break
 
@@ -78,19 +113,22 @@ def _enforce_strict(
         # Check if value is deliberately `None`, otherwise return error
         tokenized_keypath = _get_tokenized_keypath(key)
         nested_val: Any = source
-        MISSING_VAL_INDICATOR = "__NOTFOUND__"
         for k in tokenized_keypath:
-            if nested_val == MISSING_VAL_INDICATOR:
-                break
             match k:
-                case "*":
+                case _Token.WILDCARD:
                     # TODO: handle list unwraps - here we'll just stop checking
-                    nested_val = MISSING_VAL_INDICATOR
+                    raise ValueError(f"_Strict mode_: invalid key: {key}")
+                # Resolve negative indices against the list length (same as `get`)
+                case int() if isinstance(nested_val, list) and (
+                    -len(nested_val) <= k < len(nested_val)
+                ):
+                    nested_val = nested_val[k]
+                case str() if isinstance(nested_val, dict) and k in nested_val:
+                    nested_val = nested_val[k]
                 case _:
-                    nested_val = (
-                        nested_val[k]
-                        if (isinstance(k, int) or k in nested_val)
-                        else MISSING_VAL_INDICATOR
+                    raise ValueError(
+                        f"_Strict mode_: invalid key: {key}, "
+                        f"`{k}` not found in {type(nested_val).__name__}"
                     )
         if nested_val is not None:
             raise ValueError(f"_Strict mode_: invalid key: {key}")
@@ -118,21 +156,35 @@ def _nested_get(
 
     If using pydian defaults, the following benefits apply:
     - Tuple support
+    - Recursive descent (`..`) support
 
     If you use a custom `dsl_fn`, then logic is entrusted to that function (wgpcgr).
     """
     # Assume `key: str`. If not, then trust the custom `dsl_fn` to handle it
+    # Handle recursive descent: evaluate the rest of the key at every nested dict
+    if isinstance(key, str) and (i := find_recursive_descent(key)) != -1:
+        prefix, rest = key[:i], key[i + 2 :]
+        base = _nested_get(source, prefix, dsl_fn=dsl_fn) if prefix else source
+        if base is None:
+            return default
+        matches: list[Any] = []
+        for d in _iter_nested_dicts(base):
+            if (r := _nested_get(d, rest, dsl_fn=dsl_fn)) is not None:
+                matches.append(r)
+        return matches
     # Handle tuple syntax (if they ask for a tuple, return a tuple)
-    if isinstance(key, str) and ("(" in key and ")" in key):
-        key = key.replace("(", "[").replace(")", "]")
-        res = dsl_fn(source, key)
+    #  A compiled key is already rewritten, and keeps the original key as its `expression`
+    key_str = key.expression if isinstance(key, ParsedResult) else key
+    if isinstance(key_str, str) and (tuple_key := rewrite_tuples(key_str)) != key_str:
+        res = dsl_fn(source, key if isinstance(key, ParsedResult) else tuple_key)
         if isinstance(res, list):
             res = tuple(res)
     else:
         res = dsl_fn(source, key)
 
     # DSL-independent cleanup
-    if isinstance(res, list):
+    #  Only rebuild lists that have a `None` to replace, so other results aren't copied
+    if isinstance(res, list) and default is not None and any(r is None for r in res):
         res = [r if r is not None else default for r in res]
     if res is None:
         res = default
@@ -140,8 +192,21 @@ def _nested_get(
     return res
 
 
+def _iter_nested_dicts(source: Any) -> Iterator[dict[str, Any]]:
+    """
+    Yields `source` and every dict nested within it (depth-first, in order)
+    """
+    if isinstance(source, dict):
+        yield source
+        for v in source.values():
+            yield from _iter_nested_dicts(v)
+    elif isinstance(source, list):
+        for v in source:
+            yield from _iter_nested_dicts(v)
+
+
 def _nested_set(
-    source: dict[str, Any], tokenized_key_list: Sequence[str | int], target: Any
+    source: dict[str, Any], tokenized_key_list: Sequence[str | int | _Token], target: Any
 ) -> dict[str, Any] | None:
     """
     Returns a copy of source with the replace if successful, else None.
@@ -156,15 +221,42 @@ def _nested_set(
     return source
 
 
-def _get_tokenized_keypath(key: str) -> tuple[str | int, ...]:
+def _get_tokenized_keypath(key: str) -> _Keypath:
     """
     Returns a keypath with str and ints separated. Prefer tuples so it is hashable.
 
     E.g.: "a[0].b[-1].c" -> ("a", 0, "b", -1, "c")
+
+    Keys containing `.` or `[]` can be double-quoted (same as JMESPath), and are kept as-is:
+      'a."us.core.extension"[0]' -> ("a", "us.core.extension", 0)
     """
-    tokenized_key = key.replace("[", ".").replace("]", "")
-    keypath = tokenized_key.split(".")
-    return tuple(int(k) if k.removeprefix("-").isnumeric() else k for k in keypath)
+    res: list[str | int | _Token] = []
+    for m in REGEX_KEYPATH_TOKEN.finditer(key):
+        quoted, bracketed, plain = m.groups()
+        if quoted is not None:
+            # Quoted identifiers are JSON strings, e.g. to allow escaped `"`
+            res.append(json.loads(f'"{quoted}"'))
+        else:
+            k = bracketed if bracketed is not None else plain
+            if k == "*":
+                res.append(_Token.WILDCARD)
+            else:
+                res.append(int(k) if k.removeprefix("-").isnumeric() else k)
+    return tuple(res)
+
+
+def iter_ndjson(
+    lines: Iterable[str | bytes], key: str | None = None, **kwargs: Any
+) -> Iterator[Any]:
+    """
+    Parses NDJSON (one JSON value per line) lazily, e.g. from an open file. Blank lines are skipped
+
+    If `key` is provided, yields `get(record, key, **kwargs)` for each record instead
+    """
+    for line in lines:
+        if line.strip():
+            record = json.loads(line)
+            yield get(record, key, **kwargs) if key is not None else record
 
 
 def drop_keys(source: dict[str, Any], keys_to_drop: Iterable[str]) -> dict[str, Any]:
@@ -198,7 +290,7 @@ def drop_keys(source: dict[str, Any], keys_to_drop: Iterable[str]) -> dict[str,
     return res
 
 
-def impute_enum_values(source: dict[str, Any], keys_to_impute: set[str]) -> dict[str, Any]:
+def impute_enum_values(source: dict[str, Any], keys_to_impute: Iterable[str]) -> dict[str, Any]:
     """
     Returns the dictionary with the Enum values set to their corresponding `.value`
     """
@@ -209,3 +301,493 @@ def impute_enum_values(source: dict[str, Any], keys_to_impute: set[str]) -> dict
             literal_val = curr_val.value
             res = _nested_set(res, _get_tokenized_keypath(key), literal_val)  # type: ignore
     return res
+
+
+def apply_merge_patch(source: dict[str, Any], patch: Any) -> Any:
+    """
+    Returns a copy of `source` with `patch` applied using JSON Merge Patch (RFC 7386) semantics:
+    - `None` values delete the corresponding key
+    - `dict` values are merged recursively, anything else (including lists) replaces the value
+    """
+    if not isinstance(patch, dict):
+        return deepcopy(patch)
+    return _merge_patch(deepcopy(source), patch)
+
+
+def _merge_patch(target: Any, patch: dict[str, Any]) -> dict[str, Any]:
+    res = target if isinstance(target, dict) else {}
+    for k, v in patch.items():
+        if v is None:
+            res.pop(k, None)
+        elif isinstance(v, dict):
+            res[k] = _merge_patch(res.get(k), v)
+        else:
+            res[k] = deepcopy(v)
+    return res
+
+
+def merge(
+    base: Any,
+    other: Any,
+    lists: ListMerge = ListMerge.REPLACE,
+    nones: NoneMerge = NoneMerge.KEEP,
+) -> Any:
+    """
+    Returns a deep merge of `other` into `base` (neither is modified)
+
+    Dicts are merged recursively. Lists are combined based on `lists`, and `None` values
+      in `other` are handled based on `nones`. Anything else from `other` replaces the value
+    """
+    return _merge(deepcopy(base), deepcopy(other), lists, nones)
+
+
+def _merge(base: Any, other: Any, lists: ListMerge, nones: NoneMerge) -> Any:
+    match base, other:
+        case dict(), dict():
+            for k, v in other.items():
+                if v is None and nones != NoneMerge.KEEP:
+                    if nones == NoneMerge.DELETE:
+                        base.pop(k, None)
+                elif k in base:
+                    base[k] = _merge(base[k], v, lists, nones)
+                else:
+                    # Merge new dicts into an empty dict, so `nones` applies at every depth
+                    base[k] = _merge({}, v, lists, nones) if isinstance(v, dict) else v
+            return base
+        case list(), list() if lists == ListMerge.CONCAT:
+            return base + other
+        case list(), list() if lists == ListMerge.BY_INDEX:
+            res = [_merge(b, o, lists, nones) for b, o in zip(base, other)]
+            return res + (base[len(other) :] if len(base) > len(other) else other[len(base) :])
+    return other
+
+
+def set_value(
+    source: dict[str, Any] | list[Any], key: str, value: Any, create: bool = True
+) -> dict[str, Any] | list[Any]:
+    """
+    Returns a copy of `source` with `value` set at `key` (`source` is not modified).
+
+    Intermediate dicts and lists are created as needed (on a list index past the end,
+      the list is padded with `None`). If `create` is `False`, raises a `ValueError` instead.
+      Adding a new key to an existing dict is allowed either way (nothing needs to be created)
+
+    `key` supports the `.` and index syntax from `get`, e.g. "a.b[2].c".
+      The empty key `""` raises a `ValueError` (there is no parent to set on)
+    """
+    res = deepcopy(source)
+    _set_in_place(res, key, value, create)
+    return res
+
+
+def flatten_dict(source: dict[str, Any], key_prefix: str = "") -> dict[str, Any]:
+    """
+    Flattens nested dicts and lists into a single-level dict, keyed by `get` keypaths
+    E.g. Given:    {"a": {"b": 1, "c": [{"d": 2}, 3]}}
+         Returns:  {"a.b": 1, "a.c[0].d": 2, "a.c[1]": 3}
+
+    Empty dicts and lists are kept as values
+    """
+    res: dict[str, Any] = {}
+    for k, v in source.items():
+        res |= _flatten_value(v, _join_keypath(key_prefix, quote_key(k)))
+    return res
+
+
+def _flatten_value(value: Any, key: str) -> dict[str, Any]:
+    match value:
+        case dict() if value:
+            return flatten_dict(value, key)
+        case list() if value:
+            res: dict[str, Any] = {}
+            for i, item in enumerate(value):
+                res |= _flatten_value(item, f"{key}[{i}]")
+            return res
+    return {key: value}
+
+
+def unflatten_dict(source: dict[str, Any]) -> dict[str, Any]:
+    """
+    Reverses `flatten_dict`, building nested dicts and lists from `get` keypaths
+    E.g. Given:    {"a.b": 1, "a.c[0].d": 2, "a.c[1]": 3}
+         Returns:  {"a": {"b": 1, "c": [{"d": 2}, 3]}}
+
+    Keys are set in-order, same as repeated calls to `set_value`
+    """
+    res: dict[str, Any] = {}
+    for k, v in deepcopy(source).items():
+        _set_in_place(res, k, v, create=True)
+    return res
+
+
+def _set_in_place(source: dict[str, Any] | list[Any], key: str, value: Any, create: bool) -> None:
+    keypath = _get_tokenized_keypath(key)
+    if not keypath:
+        raise ValueError(f"Cannot set a value without a key, got key: {key!r}")
+    curr: Any = source
+    for i, k in enumerate(keypath):
+        is_last = i == len(keypath) - 1
+        match k:
+            case _Token.WILDCARD:
+                raise ValueError(f"`set_value` doesn't support `[*]`, got key: {key}")
+            case int() if isinstance(curr, list):
+                if not (-len(curr) <= k < len(curr)):
+                    if not create or k < 0:
+                        raise ValueError(f"Index {k} out of range at key: {key}")
+                    curr.extend([None] * (k + 1 - len(curr)))
+            case str() if isinstance(curr, dict):
+                pass
+            case _:
+                raise ValueError(f"Cannot set {k} on type {type(curr)} at key: {key}")
+        if is_last:
+            curr[k] = value
+        else:
+            nested_val = curr.get(k) if isinstance(curr, dict) else curr[k]
+            if nested_val is None:
+                if not create:
+                    raise ValueError(f"Missing {k} at key: {key}")
+                curr[k] = [] if isinstance(keypath[i + 1], int) else {}
+            curr = curr[k]
+
+
+def delete(source: dict[str, Any] | list[Any], key: str) -> dict[str, Any] | list[Any]:
+    """
+    Returns a copy of `source` with the value at `key` removed (`source` is not modified).
+      Unlike `DROP` (which flags values for the `Mapper`), the dict key or list item is removed.
+
+    Use `[*]` to remove from each item in a list, e.g. "items[*].internal_id",
+      or `.*` to remove from each value in a dict, e.g. "config.*.secret".
+      Keys that aren't found are skipped.
+    """
+    res = deepcopy(source)
+    for parent, k in list(_iter_key_parents(res, _get_tokenized_keypath(key))):
+        match k:
+            case _Token.WILDCARD if isinstance(parent, (list, dict)):
+                parent.clear()
+            case int() if isinstance(parent, list) and -len(parent) <= k < len(parent):
+                del parent[k]
+            case str() if isinstance(parent, dict):
+                parent.pop(k, None)
+    return res
+
+
+def update_at(
+    source: dict[str, Any] | list[Any], key: str, func: ApplyFunc
+) -> dict[str, Any] | list[Any]:
+    """
+    Returns a copy of `source` with `func` applied to the value at `key` (`source` is not modified).
+
+    Use `[*]` to apply to each item in a list, e.g. "items[*].price",
+      or `.*` to apply to each value in a dict, e.g. "totals.*".
+      Keys that aren't found are skipped.
+    """
+    res = deepcopy(source)
+    for parent, k in list(_iter_key_parents(res, _get_tokenized_keypath(key))):
+        match k:
+            case _Token.WILDCARD if isinstance(parent, list):
+                parent[:] = [func(v) for v in parent]
+            case _Token.WILDCARD if isinstance(parent, dict):
+                for pk, v in parent.items():
+                    parent[pk] = func(v)
+            case int() if isinstance(parent, list) and -len(parent) <= k < len(parent):
+                parent[k] = func(parent[k])
+            case str() if isinstance(parent, dict) and k in parent:
+                parent[k] = func(parent[k])
+    return res
+
+
+def iter_matches(source: dict[str, Any] | list[Any], key: str) -> Iterator[tuple[str, Any]]:
+    """
+    Lazily yields each `(concrete_key, value)` pair that exists at `key`, expanding `[*]`
+      over list items and `.*` over dict values, e.g. for "data[*].id":
+      ("data[0].id", "abc"), ("data[1].id", "def"), ...
+
+    Each `concrete_key` can be passed back to `get`, `set_value`, etc.
+      The empty key `""` raises a `ValueError` (same as `has_path` and `count_matches`)
+    """
+    return _iter_matches(source, _get_match_keypath(key), "")
+
+
+def has_path(source: dict[str, Any] | list[Any], key: str) -> bool:
+    """
+    Checks if `key` exists in `source`, including keys set to `None` (unlike `get`).
+      Stops at the first match.
+
+    `key` supports the `.`, index, and `[*]` syntax from `delete`, e.g. "items[*].id".
+      The empty key `""` raises a `ValueError`
+    """
+    return any(_count_key(p, k) for p, k in _iter_key_parents(source, _get_match_keypath(key)))
+
+
+def count_matches(source: dict[str, Any] | list[Any], key: str) -> int:
+    """
+    Counts how many values exist at `key` in `source`, including values set to `None`
+
+    `key` supports the `.`, index, and `[*]` syntax from `delete`, e.g. "items[*].id".
+      The empty key `""` raises a `ValueError`
+    """
+    return sum(_count_key(p, k) for p, k in _iter_key_parents(source, _get_match_keypath(key)))
+
+
+def _get_match_keypath(key: str) -> _Keypath:
+    if not (keypath := _get_tokenized_keypath(key)):
+        raise ValueError(f"Cannot match without a key, got key: {key!r}")
+    return keypath
+
+
+def _count_key(parent: Any, k: str | int | _Token) -> int:
+    match k:
+        case _Token.WILDCARD if isinstance(parent, (list, dict)):
+            return len(parent)
+        case int() if isinstance(parent, list) and -len(parent) <= k < len(parent):
+            return 1
+        case str() if isinstance(parent, dict) and k in parent:
+            return 1
+    return 0
+
+
+def _iter_matches(
+    source: Any, keypath: _Keypath, key_prefix: str
+) -> Iterator[tuple[str, Any]]:
+    if not keypath:
+        yield key_prefix, source
+        return
+    k, rest = keypath[0], keypath[1:]
+    match k:
+        case _Token.WILDCARD if isinstance(source, list):
+            for i, item in enumerate(source):
+                yield from _iter_matches(item, rest, f"{key_prefix}[{i}]")
+        case _Token.WILDCARD if isinstance(source, dict):
+            for dk, v in source.items():
+                yield from _iter_matches(v, rest, _join_keypath(key_prefix, quote_key(dk)))
+        case int() if isinstance(source, list) and -len(source) <= k < len(source):
+            # Report the concrete (positive) index, e.g. `[-1]` -> `[2]` for a len=3 list
+            i = k % len(source)
+            yield from _iter_matches(source[i], rest, f"{key_prefix}[{i}]")
+        case str() if isinstance(source, dict) and k in source:
+            yield from _iter_matches(source[k], rest, _join_keypath(key_prefix, quote_key(k)))
+
+
+def _iter_key_parents(
+    source: Any, keypath: _Keypath
+) -> Iterator[tuple[Any, str | int | _Token]]:
+    """
+    Yields each `(container, last_key)` pair where the `keypath` (excluding the last key) exists,
+      expanding `*` over list items and dict values
+    """
+    if not keypath:
+        return
+    if len(keypath) == 1:
+        yield source, keypath[0]
+        return
+    k, rest = keypath[0], keypath[1:]
+    match k:
+        case _Token.WILDCARD if isinstance(source, (list, dict)):
+            for item in source.values() if isinstance(source, dict) else source:
+                yield from _iter_key_parents(item, rest)
+        case int() if isinstance(source, list) and -len(source) <= k < len(source):
+            yield from _iter_key_parents(source[k], rest)
+        case str() if isinstance(source, dict) and k in source:
+            yield from _iter_key_parents(source[k], rest)
+
+
+def diff(first: Any, second: Any, key_prefix: str = "") -> list[dict[str, Any]]:
+    """
+    Returns the operations (in JSON Patch, RFC 6902 shape) that turn `first` into `second`,
+      e.g. [{"op": "replace", "path": "a.b[0]", "value": 1}, {"op": "remove", "path": "c"}]
+
+    Paths use the `get` key syntax. Dicts are compared by key and lists are compared by index.
+      The empty path `""` refers to the whole value. Use `apply_patch` to apply the result
+    """
+    res: list[dict[str, Any]] = []
+    match first, second:
+        case dict(), dict():
+            for k, v in first.items():
+                curr_key = _join_keypath(key_prefix, quote_key(k))
+                if k in second:
+                    res.extend(diff(v, second[k], curr_key))
+                else:
+                    res.append({"op": "remove", "path": curr_key})
+            for k, v in second.items():
+                if k not in first:
+                    curr_key = _join_keypath(key_prefix, quote_key(k))
+                    res.append({"op": "add", "path": curr_key, "value": deepcopy(v)})
+        case list(), list():
+            for i, (f, s) in enumerate(zip(first, second)):
+                res.extend(diff(f, s, f"{key_prefix}[{i}]"))
+            for i in range(len(first), len(second)):
+                add_op = {"op": "add", "path": f"{key_prefix}[{i}]", "value": deepcopy(second[i])}
+                res.append(add_op)
+            # Remove from the end, so earlier indices stay the same
+            for i in reversed(range(len(second), len(first))):
+                res.append({"op": "remove", "path": f"{key_prefix}[{i}]"})
+        case _ if type(first) != type(second) or first != second:
+            res.append({"op": "replace", "path": key_prefix, "value": deepcopy(second)})
+    return res
+
+
+def apply_patch(source: Any, ops: Iterable[dict[str, Any]]) -> Any:
+    """
+    Returns a copy of `source` with the JSON Patch (RFC 6902) operations applied in-order
+      (`add`, `remove`, `replace`, `move`, `copy`, `test`, with paths in the `get` key syntax)
+
+    If any operation is malformed or fails, raises a `ValueError` (`source` is not modified).
+      `test` compares like `diff`, so e.g. `1`, `1.0` and `True` are all different
+    """
+    ops = list(ops)
+    for op in ops:
+        _check_patch_op(op)
+    res = deepcopy(source)
+    for op in ops:
+        path = op["path"]
+        match op["op"]:
+            case "add":
+                res = _patch_add(res, path, deepcopy(op["value"]))
+            case "remove":
+                res = _patch_remove(res, path)[0]
+            case "replace":
+                res = _patch_replace(res, path, deepcopy(op["value"]))
+            case "move":
+                res, v = _patch_remove(res, op["from"])
+                res = _patch_add(res, path, v)
+            case "copy":
+                v = _patch_walk(res, _get_tokenized_keypath(op["from"]), op["from"])
+                res = _patch_add(res, path, deepcopy(v))
+            case "test":
+                if diff(_patch_walk(res, _get_tokenized_keypath(path), path), op["value"]):
+                    raise ValueError(f"Test failed at path: {path}, operation: {op}")
+    return res
+
+
+def _check_patch_op(op: Any) -> None:
+    if not isinstance(op, dict):
+        raise ValueError(f"Expected operation to be a dict, got: {op}")
+    if op.get("op") not in ("add", "remove", "replace", "move", "copy", "test"):
+        raise ValueError(f"Unsupported operation: {op}")
+    if not isinstance(op.get("path"), str):
+        raise ValueError(f"Missing `path` in operation: {op}")
+    if op["op"] in ("add", "replace", "test") and "value" not in op:
+        raise ValueError(f"Missing `value` in operation: {op}")
+    if op["op"] in ("move", "copy") and not isinstance(op.get("from"), str):
+        raise ValueError(f"Missing `from` in operation: {op}")
+
+
+def _join_keypath(key_prefix: str, key: str) -> str:
+    return f"{key_prefix}.{key}" if key_prefix != "" else key
+
+
+def _patch_walk(source: Any, keypath: _Keypath, path: str) -> Any:
+    curr = source
+    for k in keypath:
+        match k:
+            case int() if isinstance(curr, list) and -len(curr) <= k < len(curr):
+                curr = curr[k]
+            case str() if isinstance(curr, dict) and k in curr:
+                curr = curr[k]
+            case _:
+                raise ValueError(f"Missing {k} at path: {path}")
+    return curr
+
+
+def _patch_add(source: Any, path: str, value: Any) -> Any:
+    keypath = _get_tokenized_keypath(path)
+    if not keypath:
+        return value
+    parent, k = _patch_walk(source, keypath[:-1], path), keypath[-1]
+    match k:
+        case int() if isinstance(parent, list) and 0 <= k <= len(parent):
+            parent.insert(k, value)
+        case str() if isinstance(parent, dict):
+            parent[k] = value
+        case _:
+            raise ValueError(f"Cannot add {k} to type {type(parent)} at path: {path}")
+    return source
+
+
+def _patch_replace(source: Any, path: str, value: Any) -> Any:
+    keypath = _get_tokenized_keypath(path)
+    if not keypath:
+        return value
+    parent, k = _patch_walk(source, keypath[:-1], path), keypath[-1]
+    match k:
+        case int() if isinstance(parent, list) and -len(parent) <= k < len(parent):
+            parent[k] = value
+        case str() if isinstance(parent, dict) and k in parent:
+            parent[k] = value
+        case _:
+            raise ValueError(f"Missing {k} at path: {path}")
+    return source
+
+
+def _patch_remove(source: Any, path: str) -> tuple[Any, Any]:
+    keypath = _get_tokenized_keypath(path)
+    if not keypath:
+        return None, source
+    parent, k = _patch_walk(source, keypath[:-1], path), keypath[-1]
+    match k:
+        case int() if isinstance(parent, list) and -len(parent) <= k < len(parent):
+            return source, parent.pop(k)
+        case str() if isinstance(parent, dict) and k in parent:
+            return source, parent.pop(k)
+    raise ValueError(f"Missing {k} at path: {path}")
+
+
+def from_json_pointer(pointer: str) -> str:
+    """
+    Converts a JSON Pointer (RFC 6901) to a `get` key, e.g. "/a/b.c/0" -> 'a."b.c"[0]'
+
+    Numeric tokens are converted to list indices. To look up a dict key that is numeric,
+      use `get_pointer` (which checks against the source)
+    """
+    res = ""
+    for token in _get_json_pointer_tokens(pointer):
+        if _is_json_pointer_index(token):
+            res += f"[{token}]"
+        else:
+            res = _join_keypath(res, quote_key(token))
+    return res
+
+
+def to_json_pointer(key: str) -> str:
+    """
+    Converts a `get` key to a JSON Pointer (RFC 6901), e.g. 'a."b.c"[0]' -> "/a/b.c/0"
+    """
+    res = ""
+    for k in _get_tokenized_keypath(key):
+        if k == _Token.WILDCARD or (isinstance(k, int) and k < 0):
+            raise ValueError(
+                f"JSON Pointer doesn't support `*` or negative indices, got key: {key}"
+            )
+        res += "/" + str(k).replace("~", "~0").replace("/", "~1")
+    return res
+
+
+def get_pointer(source: dict[str, Any] | list[Any], pointer: str, default: Any = None) -> Any:
+    """
+    Gets a value from the source using a JSON Pointer (RFC 6901), e.g. "/data/0/patient/id"
+
+    Returns `default` if the value isn't found (or is `None`)
+    """
+    curr: Any = source
+    for token in _get_json_pointer_tokens(pointer):
+        if isinstance(curr, dict) and token in curr:
+            curr = curr[token]
+        elif isinstance(curr, list) and _is_json_pointer_index(token) and int(token) < len(curr):
+            curr = curr[int(token)]
+        else:
+            return default
+    return curr if curr is not None else default
+
+
+def _is_json_pointer_index(token: str) -> bool:
+    # Only ASCII digits, without leading zeros (RFC 6901)
+    return token.isascii() and token.isdigit() and (token == "0" or not token.startswith("0"))
+
+
+def _get_json_pointer_tokens(pointer: str) -> list[str]:
+    if pointer == "":
+        return []
+    if not pointer.startswith("/"):
+        raise ValueError(f"JSON Pointer should start with `/`, got: {pointer}")
+    # Unescape `~1` first, so `~01` becomes `~1` (not `/`)
+    return [t.replace("~1", "/").replace("~0", "~") for t in pointer[1:].split("/")]
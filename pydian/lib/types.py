@@ -49,3 +49,29 @@ class KEEP:
 
     def __init__(self, v: Any):
         self.value = v
+
+
+class ListMerge(Enum):
+    """
+    How `merge` combines two lists at the same key:
+    - REPLACE: the list from `other` is used
+    - CONCAT: the list from `other` is appended to the list from `base`
+    - BY_INDEX: items at the same index are merged, extra items from the longer list are kept
+    """
+
+    REPLACE = "replace"
+    CONCAT = "concat"
+    BY_INDEX = "by_index"
+
+
+class NoneMerge(Enum):
+    """
+    How `merge` handles a `None` value in `other` (for dict values):
+    - KEEP: the `None` is set, same as any other value
+    - SKIP: the value from `base` is kept
+    - DELETE: the key is removed (same as JSON Merge Patch)
+    """
+
+    KEEP = "keep"
+    SKIP = "skip"
+    DELETE = "delete"
@@ -1,12 +1,19 @@
 import base64
+import json
+import re
 from collections.abc import Collection
+from functools import lru_cache
 from itertools import chain
-from typing import Any, TypeVar
+from typing import Any, Iterator, TypeVar
 
 import jmespath
+from jmespath import functions
+from jmespath.parser import ParsedResult
 
 DL = TypeVar("DL", dict[str, Any], list[Any])
 
+REGEX_IDENTIFIER = re.compile(r"[A-Za-z_][A-Za-z0-9_]*")
+
 
 def remove_empty_values(input: DL) -> DL:
     """
@@ -35,13 +42,25 @@ def has_content(obj: Any) -> bool:
     return res
 
 
+def quote_key(key: Any) -> str:
+    """
+    Double-quotes a key (same as a JMESPath quoted identifier) if it isn't a plain identifier,
+      e.g. "us.core.extension" -> '"us.core.extension"'
+
+    Non-`str` keys (e.g. `int`) are left unquoted
+    """
+    if not isinstance(key, str):
+        return str(key)
+    return key if REGEX_IDENTIFIER.fullmatch(key) else json.dumps(key, ensure_ascii=False)
+
+
 def get_keys_containing_class(source: dict[str, Any], cls: type, key_prefix: str = "") -> set[str]:
     """
     Recursively finds all keys where a DROP object is found.
     """
     res = set()
     for k, v in source.items():
-        curr_key = f"{key_prefix}.{k}" if key_prefix != "" else k
+        curr_key = f"{key_prefix}.{quote_key(k)}" if key_prefix != "" else quote_key(k)
         match v:
             case cls():  # type: ignore
                 res.add(curr_key)
@@ -57,26 +76,124 @@ def get_keys_containing_class(source: dict[str, Any], cls: type, key_prefix: str
     return res
 
 
-def flatten_list(res: list[list[Any]]) -> list[Any]:
+def flatten_list(res: list[list[Any]], max_depth: int | None = None) -> list[Any]:
     """
     Flattens a list-of-list
     E.g. Given:    [[1, 2, 3], [4, 5, 6], None, [7, 8, 9]]
          Returns:  [1, 2, 3, 4, 5, 6, 7, 8, 9]
+
+    If `max_depth` is set, only flattens up to that many levels (otherwise flattens fully)
     """
+    if max_depth is not None and max_depth <= 0:
+        return res
     if res_without_nones := [l for l in res if (l is not None) and (isinstance(l, list))]:
         res = list(chain.from_iterable(res_without_nones))
         # Handle nested case
-        res = flatten_list(res)
+        res = flatten_list(res, max_depth - 1 if max_depth is not None else None)
     return res
 
 
-def default_dsl(source: dict[str, Any] | list[Any], key: str):
+def iter_unquoted_chars(key: str) -> Iterator[tuple[int, str]]:
+    """
+    Yields `(index, char)` for each char in `key` that is outside of quotes, i.e. JMESPath
+      quoted identifiers (`"`), raw strings (`'`), and literals (`` ` ``)
+    """
+    quote: str | None = None
+    escaped = False
+    for i, c in enumerate(key):
+        if quote:
+            if escaped:
+                escaped = False
+            elif c == "\\":
+                escaped = True
+            elif c == quote:
+                quote = None
+        elif c in "\"'`":
+            quote = c
+        else:
+            yield i, c
+
+
+def find_recursive_descent(key: str) -> int:
+    """
+    Returns the index of the first `..` outside of quotes, else -1
+    """
+    prev_dot = -2
+    for i, c in iter_unquoted_chars(key):
+        if c == ".":
+            if prev_dot == i - 1:
+                return prev_dot
+            prev_dot = i
+    return -1
+
+
+def rewrite_tuples(key: str) -> str:
+    """
+    Rewrites tuple parens to JMESPath multiselect brackets,
+      e.g. "a.(b, c.(d, e))" -> "a.[b, c.[d, e]]"
+
+    A paren starts a tuple if it starts a segment (at the start, or after `.`, `|`, `,`, `[`,
+      or another tuple). Other parens are kept, e.g. function calls like `sum(a)`
+    """
+    res = list(key)
+    is_tuple_stack: list[bool] = []
+    for i, c in iter_unquoted_chars(key):
+        if c == "(":
+            before = key[:i].rstrip()
+            starts_segment = before[-1:] in ("", ".", "|", ",", "[") and not before.endswith("||")
+            in_tuple = before.endswith("(") and bool(is_tuple_stack) and is_tuple_stack[-1]
+            is_tuple = starts_segment or in_tuple
+            is_tuple_stack.append(is_tuple)
+            if is_tuple:
+                res[i] = "["
+        elif c == ")" and is_tuple_stack and is_tuple_stack.pop():
+            res[i] = "]"
+    return "".join(res)
+
+
+class PydianFunctions(functions.Functions):
+    """
+    Extends the JMESPath built-ins (`sum`, `avg`, `min`, `max`, `abs`, `length`, etc.)
+      with additional helpers for aggregating values within a `get` key, e.g.:
+      "data[*].value | round(avg(@), `2`)"
+    """
+
+    @functions.signature({"types": ["number"]}, {"types": ["number"]})
+    def _func_round(self, value, ndigits):
+        return round(value, int(ndigits))
+
+    @functions.signature({"types": ["array"]})
+    def _func_count(self, arr):
+        # Unlike `length`, only counts non-`None` values (similar to SQL `COUNT`)
+        return sum(1 for v in arr if v is not None)
+
+
+_JMESPATH_OPTIONS = jmespath.Options(custom_functions=PydianFunctions())
+
+
+@lru_cache(maxsize=1024)
+def compile_key(key: str) -> ParsedResult:
+    """
+    Parses a `get` key once, so repeated `get` calls with the same key skip parsing
+
+    The result can also be passed as the `key` to `get`, e.g. to reuse across many documents.
+      Tuples are supported, but `..` raises a `ValueError` (it can't be compiled ahead of time)
+    """
+    if find_recursive_descent(key) != -1:
+        raise ValueError(f"Recursive descent (`..`) can't be compiled, got key: {key}")
+    # Keep the original key as the `expression` (e.g. for error messages)
+    return ParsedResult(key, jmespath.compile(rewrite_tuples(key)).parsed)
+
+
+def default_dsl(source: dict[str, Any] | list[Any], key: str | ParsedResult):
     """
     Specifies a DSL (domain-specific language) to use when running `get`
 
     Here, we redefine the `jmespath.search` to be consistent with argument ordering in the repo
+      (`key` can also be an already compiled key from `compile_key`)
     """
-    return jmespath.search(key, source)
+    parsed = key if isinstance(key, ParsedResult) else compile_key(key)
+    return parsed.search(source, options=_JMESPATH_OPTIONS)
 
 
 def encode_stack_trace(stack_trace: list[str]) -> str:
@@ -47,9 +47,10 @@ class Mapper:
         res = self.map_fn(source, **kwargs)
 
         # Handle any DROP-flagged values
+        #  Keys are sorted so DROP/KEEP handling is deterministic across runs
         keys_to_drop = get_keys_containing_class(res, DROP)
         if keys_to_drop:
-            res = drop_keys(res, keys_to_drop)
+            res = drop_keys(res, sorted(keys_to_drop))
 
         # Remove empty values
         if self.remove_empty:
@@ -58,6 +59,6 @@ class Mapper:
         # Impute KEEP values with corresponding value
         keys_to_impute = get_keys_containing_class(res, KEEP)
         if keys_to_impute:
-            res = impute_enum_values(res, keys_to_impute)
+            res = impute_enum_values(res, sorted(keys_to_impute))
 
         return res
@@ -1,7 +1,11 @@
+from collections import defaultdict
+from datetime import datetime
 from functools import partial
 from itertools import islice
 from typing import Any, Callable, Container, Iterable, Reversible, TypeAlias, TypeVar
 
+from jmespath.parser import ParsedResult
+
 import pydian
 from pydian.lib.types import DROP, ApplyFunc, ConditionalCheck
 
@@ -11,12 +15,12 @@ from pydian.lib.types import DROP, ApplyFunc, ConditionalCheck
 
 
 def get(
-    key: str,
+    key: str | ParsedResult,
     default: Any = None,
     apply: ApplyFunc | Iterable[ApplyFunc] | None = None,
     only_if: ConditionalCheck | None = None,
     drop_level: DROP | None = None,
-    flatten: bool | None = None,
+    flatten: bool | int | None = None,
 ):
     """
     Partial wrapper around the Pydian `get` function
@@ -139,6 +143,161 @@ def not_contained_in(container: Container) -> ConditionalCheck:
     return lambda v: v not in container
 
 
+def matches(expr: str) -> ConditionalCheck:
+    """
+    Evaluates a boolean `get` expression against the value,
+      e.g. "patient.active && length(patient.ints[?@ > `5`]) > `0`"
+
+    Uses JMESPath truthiness (same as filters like `[?n]`), so e.g. `0` is truthy, and only
+      `None`, `False`, and empty strings/lists/dicts are falsy
+    """
+
+    def _matches(v: Any) -> bool:
+        res = pydian.get(v, expr)
+        if isinstance(res, (str, list, tuple, dict)):
+            return len(res) > 0
+        return res is not None and res is not False
+
+    return _matches
+
+
+"""
+List Wrappers
+"""
+
+
+def group_by(key: str) -> ApplyFunc | Callable[[Iterable[Any]], dict[Any, list[Any]]]:
+    """
+    Groups a list of dicts by the value at `key` (using `get`), e.g. for `key="k"`:
+      [{"k": "a"}, {"k": "b"}, {"k": "a"}] -> {"a": [{"k": "a"}, {"k": "a"}], "b": [{"k": "b"}]}
+
+    Use the tuple syntax to group on multiple keys, e.g. `"(k, other.k)"`.
+      Values that are lists are grouped as tuples, e.g. `[1, 2]` -> `(1, 2)`
+    """
+
+    def _group_by(it: Iterable[Any]) -> dict[Any, list[Any]]:
+        res: defaultdict[Any, list[Any]] = defaultdict(list)
+        for item in it:
+            res[_to_hashable_key(pydian.get(item, key), key)].append(item)
+        return dict(res)
+
+    return _group_by
+
+
+def _to_hashable_key(v: Any, key: str) -> Any:
+    match v:
+        case list() | tuple():
+            return tuple(_to_hashable_key(item, key) for item in v)
+        case dict():
+            raise ValueError(f"Cannot group by a dict value, got: {v} at key: {key}")
+    try:
+        hash(v)
+    except TypeError:
+        raise ValueError(f"Cannot group by an unhashable value, got: {v} at key: {key}")
+    return v
+
+
+def sort_by(
+    key: str | list[str], descending: bool | list[bool] = False, nulls_last: bool = True
+) -> ApplyFunc | Callable[[Iterable[Any]], list[Any]]:
+    """
+    Stable sort of a list of dicts by the value at `key` (using `get`)
+
+    For multiple keys, earlier keys take precedence. `descending` can be set per-key.
+      Items where the value is `None` are placed according to `nulls_last`
+    """
+    keys = [key] if isinstance(key, str) else key
+    desc = [descending] * len(keys) if isinstance(descending, bool) else descending
+    if len(keys) != len(desc):
+        raise ValueError(f"Expected one `descending` value per key, got: {keys} {desc}")
+
+    def _sort_by(it: Iterable[Any]) -> list[Any]:
+        res = list(it)
+        # Sort by the least significant key first, since each pass is stable
+        for k, d in reversed(list(zip(keys, desc))):
+            pairs = [(item, pydian.get(item, k)) for item in res]
+            has_val = sorted(
+                (iv for iv in pairs if iv[1] is not None), key=lambda iv: iv[1], reverse=d
+            )
+            nulls = [iv for iv in pairs if iv[1] is None]
+            res = [item for item, _ in (has_val + nulls if nulls_last else nulls + has_val)]
+        return res
+
+    return _sort_by
+
+
+def distinct(key: str | None = None) -> ApplyFunc | Callable[[Iterable[Any]], list[Any]]:
+    """
+    Removes duplicates from a list, keeping the first occurrence of each item (order is kept)
+
+    If `key` is provided, items are compared by the value at `key` (using `get`).
+      Values of different types are distinct, e.g. `1`, `1.0`, and `True`
+    """
+
+    def _distinct(it: Iterable[Any]) -> list[Any]:
+        res: list[Any] = []
+        seen: set[tuple[type, Any]] = set()
+        # Values can be unhashable (e.g. dicts), so fall back to comparing by equality
+        seen_unhashable: list[tuple[type, Any]] = []
+        for item in it:
+            v = pydian.get(item, key) if key else item
+            typed_v = (type(v), v)
+            try:
+                if typed_v in seen:
+                    continue
+                seen.add(typed_v)
+            except TypeError:
+                if typed_v in seen_unhashable:
+                    continue
+                seen_unhashable.append(typed_v)
+            res.append(item)
+        return res
+
+    return _distinct
+
+
+def left_join(
+    second: Iterable[Any], on: str, second_on: str | None = None
+) -> ApplyFunc | Callable[[Iterable[Any]], list[Any]]:
+    """
+    Left join of a list of dicts with `second`, matching `on` with `second_on` (defaults to `on`)
+
+    Matched items are merged (values from `second` take precedence), unmatched items are kept
+    """
+    return partial(_join_lists, second=second, on=on, second_on=second_on, keep_unmatched=True)
+
+
+def inner_join(
+    second: Iterable[Any], on: str, second_on: str | None = None
+) -> ApplyFunc | Callable[[Iterable[Any]], list[Any]]:
+    """
+    Inner join of a list of dicts with `second`, matching `on` with `second_on` (defaults to `on`)
+
+    Matched items are merged (values from `second` take precedence), unmatched items are dropped
+    """
+    return partial(_join_lists, second=second, on=on, second_on=second_on, keep_unmatched=False)
+
+
+def _join_lists(
+    first: Iterable[Any],
+    second: Iterable[Any],
+    on: str,
+    second_on: str | None,
+    keep_unmatched: bool,
+) -> list[Any]:
+    lookup = group_by(second_on or on)(second)
+    res: list[Any] = []
+    for item in first:
+        k = pydian.get(item, on)
+        # Similar to SQL, `None` keys don't match
+        matched = lookup.get(_to_hashable_key(k, on), []) if k is not None else []
+        if matched:
+            res.extend({**item, **m} for m in matched)
+        elif keep_unmatched:
+            res.append(item)
+    return res
+
+
 """
 stdlib Wrappers
 """
@@ -160,6 +319,40 @@ def filter_to_list(func: Callable) -> ApplyFunc | Callable[[Iterable], list[Any]
     return partial(_filter_to_list, func)
 
 
+DEFAULT_DATE_FORMATS = (
+    "%Y-%m-%d",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y%m%d",
+    "%m/%d/%Y",
+    "%d %b %Y",
+    "%b %d, %Y",
+)
+
+
+def format_date(
+    fmt: str, input_fmts: Iterable[str] = DEFAULT_DATE_FORMATS, with_input_fmt: bool = False
+) -> ApplyFunc | Callable[[str], str | tuple[str, str] | None]:
+    """
+    Partial wrapper for `datetime.strptime`, then `strftime` with `fmt`
+
+    Each of `input_fmts` is tried in-order, returns `None` if none match (or if not a `str`).
+      If `with_input_fmt` is set, returns a `(formatted, matched_input_fmt)` tuple instead
+    """
+    # Materialize once, so e.g. a generator isn't exhausted after the first call
+    input_fmts = tuple(input_fmts)
+
+    def _format_date(s: str) -> str | tuple[str, str] | None:
+        for in_fmt in input_fmts:
+            try:
+                res = datetime.strptime(s, in_fmt).strftime(fmt)
+            except (ValueError, TypeError):
+                continue
+            return (res, in_fmt) if with_input_fmt else res
+        return None
+
+    return _format_date
+
+
 """
 DataFrame Wrappers
 """
@@ -1,4 +1,16 @@
-from pydian.lib.util import flatten_list, remove_empty_values
+from typing import Any
+
+import pytest
+
+import pydian.dicts
+import pydian.partials as p
+from pydian import get
+from pydian.lib.util import (
+    compile_key,
+    default_dsl,
+    flatten_list,
+    remove_empty_values,
+)
 
 
 def test_remove_empty_values() -> None:
@@ -18,3 +30,37 @@ def test_flatten_list() -> None:
     assert flatten_list([[1, 2], [3, 4], [5, 6]]) == [1, 2, 3, 4, 5, 6]
     # assert flatten_list([[[1], 2], [[3], 4], [[5], 6]) == [1, 2, 3, 4, 5, 6]
     assert flatten_list([[[1], [2]], [[3], [4]], [[5], [6]]]) == [1, 2, 3, 4, 5, 6]
+
+    # Limit depth
+    assert flatten_list([[[1], [2]], [[3], [4]]], max_depth=1) == [[1], [2], [3], [4]]
+    assert flatten_list([[[1], [2]], [[3], [4]]], max_depth=2) == [1, 2, 3, 4]
+    assert flatten_list([[[1], [2]], [[3], [4]]], max_depth=0) == [[[1], [2]], [[3], [4]]]
+
+
+def test_compile_key() -> None:
+    compile_key.cache_clear()
+    source = {"a": {"b": [1, 2, 3]}}
+    assert default_dsl(source, "a.b[0]") == 1
+    assert default_dsl({"a": {"b": [4]}}, "a.b[0]") == 4
+    assert compile_key.cache_info().hits == 1
+    assert compile_key("a.b[0]") is compile_key("a.b[0]")
+
+
+def test_get_compiled_key(nested_data: dict[str, Any]) -> None:
+    key = compile_key("patient.dicts[*].num")
+    assert default_dsl(nested_data["data"][0], key) == [1, 2]
+    assert [get(d, key) for d in nested_data["data"]] == [[1, 2], [3, 4], [5, 6], [7]]
+    assert get(nested_data["data"][0], key, apply=sum) == 3
+    assert p.get(key, apply=len)(nested_data["data"][3]) == 1
+    assert get(nested_data["data"][0], compile_key("missing"), default="n/a") == "n/a"
+    with pytest.raises(ValueError, match="invalid key: missing"):
+        get(nested_data["data"][0], compile_key("missing"), strict=True)
+
+    # Supports the `get` key syntax (except `..`), and is available from `pydian.dicts`
+    tuple_key = pydian.dicts.compile_key("patient.(id, dict.(char, inner.msg))")
+    assert get(nested_data["data"][0], tuple_key) == ("abc123", ["a", "A!"])
+    assert get(nested_data["data"][0], tuple_key) == get(
+        nested_data["data"][0], "patient.(id, dict.(char, inner.msg))"
+    )
+    with pytest.raises(ValueError):
+        compile_key("..id")
@@ -8,7 +8,14 @@ from polars.testing import (
 from result import Err
 
 import pydian.partials as p
-from pydian.dataframes import inner_join, left_join, select
+from pydian.dataframes import (
+    diff_frames,
+    duplicates,
+    inner_join,
+    left_join,
+    select,
+    union,
+)
 
 
 def test_select(simple_dataframe: pl.DataFrame) -> None:
@@ -259,6 +266,98 @@ def test_inner_join(simple_dataframe: pl.DataFrame) -> None:
     assert isinstance(result, Err), f"Expected Err, but got {result}"
 
 
+def test_diff_frames(simple_dataframe: pl.DataFrame) -> None:
+    source = simple_dataframe
+
+    updated = pl.DataFrame(
+        {
+            "a": [0, 1, 2, 3, 6],
+            "b": ["q", "W", "e", "r", "u"],
+            "c": [True, False, False, False, True],
+            "d": [None, None, None, None, None],
+        }
+    )
+
+    res = diff_frames(source, updated, on="a")
+    assert not isinstance(res, Err)
+    assert_frame_equal(res.added, updated.filter(pl.col("a") == 6))
+    assert_frame_equal(res.removed, source.filter(pl.col("a").is_in([4, 5])))
+    assert res.changed["a"].to_list() == [1, 2]
+    assert res.changed["b"].to_list() == ["w", "e"]
+    assert res.changed["b_second"].to_list() == ["W", "e"]
+    assert res.changed["c_second"].to_list() == [False, False]
+    assert res.changed["changed_cols"].to_list() == [["b"], ["c"]]
+
+    # No differences
+    res = diff_frames(source, source.clone(), on=["a", "b"])
+    assert not isinstance(res, Err)
+    assert res.added.is_empty() and res.removed.is_empty() and res.changed.is_empty()
+    assert "changed_cols" in res.changed.columns
+
+    # Changed column can't collide with an existing one
+    assert isinstance(diff_frames(source, updated, on="a", changed_col="b"), Err)
+
+    # Key needs to be in both
+    assert isinstance(diff_frames(source, updated.drop("b"), on="b"), Err)
+
+
+def test_union(simple_dataframe: pl.DataFrame) -> None:
+    source = simple_dataframe
+
+    other = pl.DataFrame(
+        {
+            "a": [6.5, 7.5],
+            "b": ["u", "i"],
+            "e": [1, 2],
+        }
+    )
+
+    res = union([source, other])
+    assert not isinstance(res, Err)
+    assert res.columns == ["a", "b", "c", "d", "e"]
+    assert res.height == source.height + other.height
+    assert res.schema["a"] == pl.Float64
+    assert res["a"].to_list() == [0, 1, 2, 3, 4, 5, 6.5, 7.5]
+    assert res["c"].to_list()[-2:] == [None, None]
+    assert res["e"].to_list() == [None] * source.height + [1, 2]
+
+    # Report which columns were coerced
+    res, report = union([source, other], with_report=True)  # type: ignore
+    assert res.schema["a"] == pl.Float64
+    assert report == {"a": ([pl.Int64, pl.Float64], pl.Float64)}
+
+    assert_frame_equal(union([source]), source)  # type: ignore
+    assert isinstance(union([]), Err)
+
+
+def test_duplicates() -> None:
+    source = pl.DataFrame(
+        {
+            "id": [1, 2, 1, 3, 2, 1],
+            "system": ["mrn", "mrn", "mrn", "mrn", "ssn", "ssn"],
+            "value": ["a", "b", "c", "d", "e", "f"],
+        }
+    )
+
+    assert_frame_equal(
+        duplicates(source, "id"),  # type: ignore
+        pl.DataFrame(
+            {
+                "id": [1, 2, 1, 2, 1],
+                "system": ["mrn", "mrn", "mrn", "ssn", "ssn"],
+                "value": ["a", "b", "c", "e", "f"],
+                "count": [3, 2, 3, 2, 3],
+            }
+        ),
+        check_dtypes=False,
+    )
+    assert duplicates(source, ["id", "system"])["value"].to_list() == ["a", "c"]  # type: ignore
+    assert duplicates(source, "value").is_empty()  # type: ignore
+    assert "n" in duplicates(source, "id", count_col="n").columns  # type: ignore
+    assert isinstance(duplicates(source, "missing"), Err)
+    assert isinstance(duplicates(source, "id", count_col="value"), Err)
+
+
 # def test_insert(simple_dataframe: pl.DataFrame) -> None:
 #     rows_to_insert = [{"a": 6, "b": "u", "c": False, "d": None}]
 #     expected_data = {
@@ -1,10 +1,31 @@
+import io
+import json
 from typing import Any
 
 import pytest
 
 import pydian.partials as p
 from pydian import get
-from pydian.dicts import drop_keys
+from pydian.dicts import (
+    apply_merge_patch,
+    apply_patch,
+    count_matches,
+    delete,
+    diff,
+    drop_keys,
+    flatten_dict,
+    from_json_pointer,
+    get_pointer,
+    has_path,
+    iter_matches,
+    iter_ndjson,
+    merge,
+    set_value,
+    to_json_pointer,
+    unflatten_dict,
+    update_at,
+)
+from pydian.lib.types import ListMerge, NoneMerge
 
 
 def test_get(simple_data: dict[str, Any]) -> None:
@@ -19,6 +40,18 @@ def test_get(simple_data: dict[str, Any]) -> None:
     )
 
 
+def test_get_returns_references(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    # Values are returned from the source without copying
+    assert get(source, "data") is source["data"]
+    assert get(source, "data[0].patient.ints") is source["data"][0]["patient"]["ints"]
+    assert get(source, "data[0].patient.dict") is source["data"][0]["patient"]["dict"]
+    # Unless a `None` needs to be replaced with the `default`
+    assert get({"a": [1, None]}, "a", default=0) == [1, 0]
+    assert get({"a": [1, None]}, "a") == [1, None]
+
+
 def test_get_index(simple_data: dict[str, Any]) -> None:
     source = simple_data
 
@@ -32,6 +65,15 @@ def test_get_index(simple_data: dict[str, Any]) -> None:
     assert get(source, "list_data[1:]") == source["list_data"][1:]
     assert get(source, "list_data[:2]") == source["list_data"][:2]
     assert get(source, "list_data[:]") == source["list_data"][:]
+    assert get(source, "list_data[::2]") == source["list_data"][::2]
+    assert get(source, "list_data[::-1]") == source["list_data"][::-1]
+    # Slices project onto the rest of the key
+    assert get(source, "list_data[1:3].patient.id") == [
+        p["patient"]["id"] for p in source["list_data"][1:3]
+    ]
+    assert get(source, "list_data[:2].patient.id") == [
+        p["patient"]["id"] for p in source["list_data"][:2]
+    ]
 
 
 def test_get_from_list(list_data: list[Any]) -> None:
@@ -54,6 +96,16 @@ def test_nested_get(nested_data: dict[str, Any]) -> None:
     assert get(source, "data[*].patient.ints", flatten=True) == [1, 2, 3, 4, 5, 6, 7, 8, 9]
     assert get(source, "data[*].patient.dicts[*].num") == [[1, 2], [3, 4], [5, 6], [7]]
     assert get(source, "data[*].patient.dicts[*].num", flatten=True) == [1, 2, 3, 4, 5, 6, 7]
+    assert get(source, "data[*].patient.dicts[*].[num]", flatten=1) == [
+        [1],
+        [2],
+        [3],
+        [4],
+        [5],
+        [6],
+        [7],
+    ]
+    assert get(source, "data[*].patient.dicts[*].[num]", flatten=True) == [1, 2, 3, 4, 5, 6, 7]
     assert get(source, "missing.key") is None
     assert get(source, "missing[*].key") is None
     assert get(source, "missing[*].key[*].here") is None
@@ -180,6 +232,18 @@ def test_get_nested_key_tuple(nested_data: dict[str, Any]) -> None:
     ]
 
 
+def test_get_nested_and_piped_tuple() -> None:
+    source = {"a": {"b": 1, "c": {"d": 2, "e": 3}, "x(y)": 4}}
+
+    assert get(source, "a.(b, c.(d, e))") == (1, [2, 3])
+    assert get(source, "a.c.((d, e), d)") == ([2, 3], 2)
+    assert get(source, "a | (b, c.d)") == (1, 2)
+    assert get(source, "a.c.(d, e) | sum(@)") == 5
+    # Parens in quotes or function calls aren't tuples
+    assert get(source, 'a."x(y)"') == 4
+    assert get(source, "sum(a.c.[d, e])") == 5
+
+
 def test_get_strict(nested_data: dict[str, Any]) -> None:
     source = nested_data
 
@@ -188,3 +252,546 @@ def test_get_strict(nested_data: dict[str, Any]) -> None:
     with pytest.raises(ValueError) as exc_info:
         get(source, MISSING_KEY, strict=True)
     assert get(source, MISSING_KEY) == None
+
+    # Error names the key segment that failed
+    with pytest.raises(ValueError, match="`some` not found in dict"):
+        get(source, MISSING_KEY, strict=True)
+    with pytest.raises(ValueError, match="`nope` not found in dict"):
+        get(source, "data[0].patient.nope", strict=True)
+    with pytest.raises(ValueError, match="`10` not found in list"):
+        get(source, "data[0].patient.ints[10]", strict=True)
+    with pytest.raises(ValueError, match="`abc` not found in str"):
+        get(source, "data[0].patient.id.abc", strict=True)
+
+    # Found values pass through
+    assert get(source, "data[0].patient.id", strict=True) == "abc123"
+    assert get(source, "data[-1].patient.dicts[0].num", strict=True) == 7
+
+
+def test_get_aggregations(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    # JMESPath built-ins
+    assert get(source, "data[0].patient.ints | sum(@)") == 6
+    assert get(source, "data[0].patient.ints | avg(@)") == 2
+    assert get(source, "data[0].patient.ints | max(@)") == 3
+    assert get(source, "data[0].patient.ints | min(@)") == 1
+    assert get(source, "data[*].patient.ints[] | sum(@)") == 45
+
+    # Additional Pydian functions
+    assert get(source, "round(data[0].patient.ints | avg(@), `0`)") == 2
+    assert get(source, "data[*].patient.dicts[].num | round(avg(@), `2`)") == 4
+    assert get(source, "data[*].patient.ints | length(@)") == 3
+    assert get(source, "data[*].patient.[id, ints[0]][] | count(@)") == 7
+
+
+def test_apply_merge_patch(simple_data: dict[str, Any]) -> None:
+    source = simple_data
+
+    patch = {
+        "data": {"patient": {"id": "xyz999", "active": None, "name": {"given": "A"}}},
+        "list_data": [{"replaced": True}],
+        "new_key": "value",
+    }
+    res = apply_merge_patch(source, patch)
+    assert res == {
+        "data": {"patient": {"id": "xyz999", "name": {"given": "A"}}},
+        "list_data": [{"replaced": True}],
+        "new_key": "value",
+    }
+    # Source is not modified
+    assert source["data"]["patient"] == {"id": "abc123", "active": True}
+
+    # Examples from RFC 7386 Appendix A
+    assert apply_merge_patch({"a": "b"}, {"a": "c"}) == {"a": "c"}
+    assert apply_merge_patch({"a": "b"}, {"b": "c"}) == {"a": "b", "b": "c"}
+    assert apply_merge_patch({"a": "b"}, {"a": None}) == {}
+    assert apply_merge_patch({"a": "b", "b": "c"}, {"a": None}) == {"b": "c"}
+    assert apply_merge_patch({"a": ["b"]}, {"a": "c"}) == {"a": "c"}
+    assert apply_merge_patch({"a": "c"}, {"a": ["b"]}) == {"a": ["b"]}
+    assert apply_merge_patch({"a": {"b": "c"}}, {"a": {"b": "d", "c": None}}) == {"a": {"b": "d"}}
+    assert apply_merge_patch({"a": [{"b": "c"}]}, {"a": [1]}) == {"a": [1]}
+    assert apply_merge_patch({"e": None}, {"a": 1}) == {"e": None, "a": 1}
+    assert apply_merge_patch({"a": "foo"}, "bar") == "bar"
+    assert apply_merge_patch({}, {"a": {"bb": {"ccc": None}}}) == {"a": {"bb": {}}}
+
+
+def test_set_value(simple_data: dict[str, Any]) -> None:
+    source = simple_data
+
+    res = set_value(source, "data.patient.id", "xyz999")
+    assert get(res, "data.patient.id") == "xyz999"
+    assert get(source, "data.patient.id") == "abc123"
+
+    res = set_value(source, "list_data[1].patient.active", False)
+    assert get(res, "list_data[*].patient.active") == [True, False, False]
+    res = set_value(source, "list_data[-1].patient.id", "last")
+    assert get(res, "list_data[2].patient.id") == "last"
+
+    # Intermediate objects are created as needed
+    res = set_value(source, "data.patient.name[0].given[1]", "B")
+    assert get(res, "data.patient.name") == [{"given": [None, "B"]}]
+    res = set_value(source, "list_data[4].patient.id", "new")
+    assert get(res, "list_data[3]") is None
+    assert get(res, "list_data[4].patient.id") == "new"
+    assert set_value([], "[1].a", 1) == [None, {"a": 1}]
+
+    # Optionally fail instead of creating
+    assert get(set_value(source, "data.patient.new_key", 1, create=False), "data.patient.new_key")
+    with pytest.raises(ValueError):
+        set_value(source, "data.patient.name[0].given", "A", create=False)
+    with pytest.raises(ValueError):
+        set_value(source, "list_data[3].patient.id", "new", create=False)
+    with pytest.raises(ValueError):
+        set_value(source, "list_data[-4].patient.id", "new")
+
+    # Invalid keys
+    with pytest.raises(ValueError):
+        set_value(source, "list_data[*].patient.id", "new")
+    with pytest.raises(ValueError):
+        set_value(source, "data.patient.id.nested", "new")
+    with pytest.raises(ValueError):
+        set_value(source, "data[0]", "new")
+    with pytest.raises(ValueError):
+        set_value(source, "", "new")
+    with pytest.raises(ValueError):
+        unflatten_dict({"": 1})
+
+
+def test_delete(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    # Dict keys
+    res = delete(source, "data[0].patient.id")
+    assert "id" not in res["data"][0]["patient"]
+    assert get(source, "data[0].patient.id") == "abc123"
+
+    # List items
+    res = delete(source, "data[1]")
+    assert get(res, "data[*].patient.id") == ["abc123", "ghi789", "jkl101112"]
+    res = delete(source, "data[-1].patient.dicts[0]")
+    assert get(res, "data[-1].patient.dicts") == []
+    res = delete(source, "data[0].patient.ints[*]")
+    assert get(res, "data[0].patient.ints") == []
+
+    # Wildcards
+    res = delete(source, "data[*].patient.dicts[*].inner")
+    assert get(res, "data[*].patient.dicts[*].inner") == [[], [], [], []]
+    assert get(res, "data[*].patient.dicts[*].num", flatten=True) == [1, 2, 3, 4, 5, 6, 7]
+    res = delete(source, "data[*].patient.ints[0]")
+    assert get(res, "data[*].patient.ints") == [[2, 3], [5, 6], [8, 9]]
+
+    # Missing keys are skipped
+    assert delete(source, "data[0].patient.missing") == source
+    assert delete(source, "missing[*].key") == source
+    assert delete(source, "data[100].patient") == source
+    assert delete(source, "data[0].patient.id.nested") == source
+
+
+def test_negative_index(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    assert get(source, "data[-1].patient.id") == "jkl101112"
+    assert get(source, "data[-2].patient.dicts[-1].text") == "six"
+    assert get(source, "data[*].patient.ints[-1]") == [3, 6, 9]
+    assert get(source, "data[-5].patient.id") is None
+
+    assert get(set_value(source, "data[-1].patient.id", "new"), "data[3].patient.id") == "new"
+    assert get(delete(source, "data[-1]"), "data[-1].patient.id") == "ghi789"
+
+    # Strict mode resolves negative indices too
+    assert get(source, "data[-1].patient.id", strict=True) == "jkl101112"
+    with pytest.raises(ValueError):
+        get(source, "data[-5].patient.id", strict=True)
+    with pytest.raises(ValueError):
+        get(source, "data[100].patient.id", strict=True)
+
+
+def test_object_wildcard() -> None:
+    source = {
+        "config": {
+            "a": {"enabled": True, "secret": "123"},
+            "b": {"enabled": False, "secret": "456"},
+            "c": {"secret": "789"},
+        }
+    }
+
+    assert get(source, "config.*.enabled") == [True, False]
+    assert get(source, "config.*.secret") == ["123", "456", "789"]
+    assert get(source, "config.*") == list(source["config"].values())
+
+    res = delete(source, "config.*.secret")
+    assert res == {"config": {"a": {"enabled": True}, "b": {"enabled": False}, "c": {}}}
+    assert delete(source, "config.*") == {"config": {}}
+    assert delete(source, "config.*.missing") == source
+
+
+def test_recursive_descent(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    assert get(source, "..id") == ["abc123", "def456", "ghi789", "jkl101112"]
+    assert get(source, "data[:2]..msg") == ["A!", "One!", "Two!", "B!", "Three!", "Four!"]
+    assert get(source, "data[0]..num") == [1, 2]
+    assert get(source, "data[-1].patient.dicts..text") == ["seven"]
+    assert get(source, "data[1]..dicts[0].inner.msg") == ["Three!"]
+    assert get(source, "..char", apply=p.keep(2)) == ["a", "b"]
+    assert get(source, "..missing") == []
+    assert get(source, "missing..id") is None
+    assert get(source, "missing..id", default="n/a") == "n/a"
+
+    # `..` within quotes is not recursive descent
+    other = {"a": {"x..y": 1, "b": [{"name": "..", "id": 2}, {"name": "c", "id": 3}]}}
+    assert get(other, 'a."x..y"') == 1
+    assert get(other, "a.b[?name == '..'].id") == [2]
+    assert get(other, "a.b[?name == `\"..\"`].id") == [2]
+    assert get(other, 'a."x..y"..id') == []
+    assert get(other, "a..id") == [2, 3]
+
+
+def test_get_conditional(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    # JMESPath `&&`/`||` act like a ternary: `cond && if_true || if_false`
+    KEY = "patient.active && patient.id || 'inactive'"
+    assert get(source["data"][0], KEY) == "abc123"
+    assert get(source["data"][1], KEY) == "inactive"
+    assert get(source, "data[*].patient.[active && id || 'inactive'][]") == [
+        "abc123",
+        "inactive",
+        "ghi789",
+        "jkl101112",
+    ]
+    assert get(source, "data[3].patient.ints && 'has ints' || 'no ints'") == "no ints"
+
+    # ... but not a real one: a falsy `if_true` (`""`, `false`, `[]`, `{}`) gives `if_false`
+    #  (unlike Python, `0` is truthy in JMESPath)
+    assert get({"patient": {"active": True, "id": ""}}, KEY) == "inactive"
+    assert get({"patient": {"active": True, "id": []}}, KEY) == "inactive"
+    assert get({"patient": {"active": True, "id": 0}}, KEY) == 0
+    # Use `apply` for a real conditional
+    active_id = lambda pt: pt.get("id") if pt.get("active") else "inactive"
+    assert get({"patient": {"active": True, "id": ""}}, "patient", apply=active_id) == ""
+
+
+def test_get_filter(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    assert get(source, "data[0].patient.dicts[?num > `1`].text") == ["two"]
+    assert get(source, "data[*].patient.dicts[?num > `2`].text", flatten=True) == [
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+    ]
+    assert get(source, "data[?patient.active].patient.id") == ["abc123", "ghi789", "jkl101112"]
+    assert get(source, "data[?patient.dict.char == 'b'].patient.id") == ["def456"]
+    assert get(source, "data[?contains(patient.ints || `[]`, `5`)].patient.id") == ["def456"]
+    assert get(source, "data[?patient.missing].patient.id") == []
+
+
+def test_quoted_keys() -> None:
+    source = {
+        "extension": {
+            "us.core.race": {"url": "http://example.com/race"},
+            "foo[bar]": [1, 2],
+            'say "hi"': "hi",
+        }
+    }
+
+    assert get(source, 'extension."us.core.race".url') == "http://example.com/race"
+    assert get(source, 'extension."foo[bar]"[1]') == 2
+    assert get(source, 'extension."say \\"hi\\""') == "hi"
+    assert get(source, "extension.us.core.race") is None
+    with pytest.raises(ValueError, match="`missing` not found in dict"):
+        get(source, 'extension."us.core.race".missing', strict=True)
+
+    assert set_value(source, 'extension."us.core.race".url', "updated")["extension"][
+        "us.core.race"
+    ] == {"url": "updated"}
+    assert delete(source, 'extension."foo[bar]"[0]')["extension"]["foo[bar]"] == [2]
+    assert "us.core.race" not in delete(source, 'extension."us.core.race"')["extension"]
+
+    # A quoted `"*"` is a literal key, not a wildcard
+    literal = {"*": 1, "a": 2}
+    assert get(literal, '"*"') == 1
+    assert list(iter_matches(literal, '"*"')) == [('"*"', 1)]
+    assert list(iter_matches(literal, "*")) == [('"*"', 1), ("a", 2)]
+    assert set_value(literal, '"*"', 5) == {"*": 5, "a": 2}
+    assert delete(literal, '"*"') == {"a": 2}
+    assert update_at(literal, '"*"', str) == {"*": "1", "a": 2}
+    assert to_json_pointer('"*"') == "/*"
+    for k, _ in iter_matches(literal, "*"):
+        assert get(set_value(literal, k, 0), k) == 0
+
+
+def test_flatten_dict() -> None:
+    assert flatten_dict({"a": {"b": 1, "c": [{"d": 2}, 3]}}) == {
+        "a.b": 1,
+        "a.c[0].d": 2,
+        "a.c[1]": 3,
+    }
+    assert flatten_dict({"a": {}, "b": [], "c": None}) == {"a": {}, "b": [], "c": None}
+    assert flatten_dict({"us.core": {"a b": [[1, 2]]}}) == {
+        '"us.core"."a b"[0][0]': 1,
+        '"us.core"."a b"[0][1]': 2,
+    }
+    assert flatten_dict({"café": {"naïve": 1}}) == {'"café"."naïve"': 1}
+    assert flatten_dict({}) == {}
+
+
+def test_unflatten_dict(nested_data: dict[str, Any]) -> None:
+    assert unflatten_dict({"a.b": 1, "a.c[0].d": 2, "a.c[1]": 3}) == {
+        "a": {"b": 1, "c": [{"d": 2}, 3]}
+    }
+    assert unflatten_dict({"a[2]": "c", '"us.core".id': 1}) == {
+        "a": [None, None, "c"],
+        "us.core": {"id": 1},
+    }
+    assert unflatten_dict({}) == {}
+    with pytest.raises(ValueError):
+        unflatten_dict({"a": 1, "a.b": 2})
+
+    # Round-trip
+    assert unflatten_dict(flatten_dict(nested_data)) == nested_data
+
+
+def test_merge(simple_data: dict[str, Any]) -> None:
+    base = {"a": {"b": 1, "c": [1, 2]}, "d": [{"e": 1}, {"e": 2}], "f": "g"}
+    other = {"a": {"c": [3], "x": None}, "d": [{"e": 3, "h": 4}], "f": None}
+
+    assert merge(base, other) == {
+        "a": {"b": 1, "c": [3], "x": None},
+        "d": [{"e": 3, "h": 4}],
+        "f": None,
+    }
+    assert merge(base, other, lists=ListMerge.CONCAT, nones=NoneMerge.SKIP) == {
+        "a": {"b": 1, "c": [1, 2, 3]},
+        "d": [{"e": 1}, {"e": 2}, {"e": 3, "h": 4}],
+        "f": "g",
+    }
+    assert merge(base, other, lists=ListMerge.BY_INDEX, nones=NoneMerge.DELETE) == {
+        "a": {"b": 1, "c": [3, 2]},
+        "d": [{"e": 3, "h": 4}, {"e": 2}],
+    }
+    assert merge(base, {"a": "replaced"}) == {**base, "a": "replaced"}
+    # `nones` applies within new keys too (same as JSON Merge Patch for DELETE)
+    new_subtree = {"a": {"b": None, "c": {"d": None, "e": 1}}}
+    assert merge({}, new_subtree, nones=NoneMerge.DELETE) == {"a": {"c": {"e": 1}}}
+    assert merge({}, new_subtree, nones=NoneMerge.DELETE) == apply_merge_patch({}, new_subtree)
+    assert merge({}, new_subtree, nones=NoneMerge.SKIP) == {"a": {"c": {"e": 1}}}
+    assert merge({}, new_subtree) == new_subtree
+    assert merge(simple_data, {}) == simple_data
+
+    # Inputs aren't modified, and the result doesn't share objects with them
+    res = merge(base, other, lists=ListMerge.BY_INDEX)
+    res["d"][0]["e"] = 100
+    assert base["d"][0] == {"e": 1} and other["d"][0] == {"e": 3, "h": 4}
+
+
+def test_diff_and_apply_patch(nested_data: dict[str, Any]) -> None:
+    first = {"a": {"b": 1, "c": [1, 2, 3]}, "d": "x", "us.core": 1, "t": 1}
+    second = {"a": {"b": 2, "c": [1]}, "e": [1], "us.core": 2, "t": True}
+
+    ops = diff(first, second)
+    assert ops == [
+        {"op": "replace", "path": "a.b", "value": 2},
+        {"op": "remove", "path": "a.c[2]"},
+        {"op": "remove", "path": "a.c[1]"},
+        {"op": "remove", "path": "d"},
+        {"op": "replace", "path": '"us.core"', "value": 2},
+        {"op": "replace", "path": "t", "value": True},
+        {"op": "add", "path": "e", "value": [1]},
+    ]
+    assert apply_patch(first, ops) == second
+    assert first["a"]["c"] == [1, 2, 3]
+    assert diff(nested_data, nested_data) == []
+    assert diff([1], [1, 2]) == [{"op": "add", "path": "[1]", "value": 2}]
+    assert apply_patch(1, diff(1, "a")) == "a"
+
+    # Round-trip
+    other = set_value(delete(nested_data, "data[1]"), "data[0].patient.dicts[1].num", 100)
+    assert apply_patch(nested_data, diff(nested_data, other)) == other
+
+    # Other operations
+    assert apply_patch(
+        {"a": [1, 2]},
+        [
+            {"op": "move", "from": "a[0]", "path": "b"},
+            {"op": "copy", "from": "a", "path": "c"},
+            {"op": "test", "path": "b", "value": 1},
+            {"op": "add", "path": "a[0]", "value": 0},
+        ],
+    ) == {"a": [0, 2], "b": 1, "c": [2]}
+
+    # Failures raise without modifying the source
+    source = {"a": 1}
+    with pytest.raises(ValueError):
+        apply_patch(
+            source, [{"op": "replace", "path": "a", "value": 2}, {"op": "remove", "path": "x"}]
+        )
+    with pytest.raises(ValueError):
+        apply_patch(source, [{"op": "test", "path": "a", "value": 2}])
+    # `test` is type-aware (same as `diff`)
+    for other_value in (True, 1.0):
+        with pytest.raises(ValueError):
+            apply_patch(source, [{"op": "test", "path": "a", "value": other_value}])
+    assert apply_patch({"a": [{"b": 1}]}, [{"op": "test", "path": "a", "value": [{"b": 1}]}])
+    with pytest.raises(ValueError):
+        apply_patch(source, [{"op": "add", "path": "a.b", "value": 2}])
+    with pytest.raises(ValueError):
+        apply_patch(source, [{"op": "unknown", "path": "a"}])
+    for bad_op in (
+        {"op": "add", "path": "b"},
+        {"op": "replace", "path": "a"},
+        {"op": "test", "path": "a"},
+        {"op": "move", "path": "b"},
+        {"op": "copy", "path": "b", "from": 0},
+        {"op": "remove"},
+        "not an op",
+    ):
+        with pytest.raises(ValueError):
+            apply_patch(source, [bad_op])  # type: ignore
+    assert source == {"a": 1}
+
+    # `replace` works in place, including negative indices
+    assert apply_patch({"a": [1, 2]}, [{"op": "replace", "path": "a[-1]", "value": 3}]) == {
+        "a": [1, 3]
+    }
+    assert apply_patch({"a": [1, 2]}, [{"op": "replace", "path": "a[0]", "value": 0}]) == {
+        "a": [0, 2]
+    }
+    with pytest.raises(ValueError):
+        apply_patch({"a": [1, 2]}, [{"op": "replace", "path": "a[2]", "value": 3}])
+
+
+def test_json_pointer(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    assert from_json_pointer("/data/0/patient/id") == "data[0].patient.id"
+    assert from_json_pointer("/a/b.c/~01x/a~1b") == 'a."b.c"."~1x"."a/b"'
+    assert from_json_pointer("") == ""
+    assert to_json_pointer("data[0].patient.id") == "/data/0/patient/id"
+    assert to_json_pointer('a."b.c"."~1x"."a/b"') == "/a/b.c/~01x/a~1b"
+    with pytest.raises(ValueError):
+        to_json_pointer("data[*].patient")
+    with pytest.raises(ValueError):
+        from_json_pointer("data/0")
+
+    # Use with `get`
+    KEY = "/data/0/patient/dicts/1/inner/msg"
+    assert get(source, from_json_pointer(KEY)) == get_pointer(source, KEY) == "Two!"
+
+    assert get_pointer(source, "") == source
+    assert get_pointer({"a": {"0": [5, 6]}}, "/a/0/1") == 6
+    assert get_pointer(source, "/data/100/patient") is None
+    assert get_pointer(source, "/data/-1", default="n/a") == "n/a"
+
+    # Only ASCII digits without leading zeros are indices
+    assert get_pointer({"a": ["x", "y"]}, "/a/²", default="n/a") == "n/a"
+    assert get_pointer({"a": ["x", "y"]}, "/a/01") is None
+    assert get_pointer({"a": {"01": "y"}}, "/a/01") == "y"
+    assert from_json_pointer("/a/01/0/10") == 'a."01"[0][10]'
+    assert from_json_pointer("/a/²") == 'a."²"'
+    assert get({"a": {"²": 1}}, from_json_pointer("/a/²")) == 1
+
+
+def test_iter_ndjson(nested_data: dict[str, Any]) -> None:
+    records = nested_data["data"]
+    ndjson = "\n".join(json.dumps(r) for r in records) + "\n\n"
+
+    assert list(iter_ndjson(io.StringIO(ndjson))) == records
+    assert list(iter_ndjson(io.BytesIO(ndjson.encode()), "patient.id")) == [
+        "abc123",
+        "def456",
+        "ghi789",
+        "jkl101112",
+    ]
+    assert list(iter_ndjson(io.StringIO(ndjson), "patient.ints[0]", default=0)) == [1, 4, 7, 0]
+    assert list(iter_ndjson([])) == []
+
+    # Records are parsed lazily
+    it = iter_ndjson(["{}", "not json"])
+    assert next(it) == {}
+    with pytest.raises(json.JSONDecodeError):
+        next(it)
+
+
+def test_has_path(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    assert has_path(source, "data[0].patient.dicts[1].inner.msg")
+    assert has_path(source, "data[-1].patient")
+    assert has_path(source, "data[*].patient.ints")
+    assert has_path({"a": None}, "a")
+    assert has_path({"a": {"us.core": 1}}, 'a."us.core"')
+    assert not has_path(source, "data[100].patient")
+    assert not has_path(source, "data[*].patient.missing")
+    assert not has_path(source, "data[0].patient.id.nope")
+    # The empty key raises (same as `set_value`)
+    with pytest.raises(ValueError):
+        has_path(source, "")
+    with pytest.raises(ValueError):
+        count_matches(source, "")
+    with pytest.raises(ValueError):
+        iter_matches(source, "")
+
+    assert count_matches(source, "data[*].patient") == 4
+    assert count_matches(source, "data[*].patient.ints") == 3
+    assert count_matches(source, "data[*].patient.dicts[*]") == 7
+    assert count_matches(source, "data[*].patient.dicts[*].inner.msg") == 7
+    assert count_matches(source, "data[0].patient.ints[*]") == 3
+    assert count_matches({"a": [None, None]}, "a[*]") == 2
+    assert count_matches(source, "missing[*]") == 0
+
+
+def test_update_at(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    res = update_at(source, "data[*].patient.id", str.upper)
+    assert get(res, "data[*].patient.id") == ["ABC123", "DEF456", "GHI789", "JKL101112"]
+    assert get(source, "data[0].patient.id") == "abc123"
+
+    res = update_at(source, "data[0].patient.ints[*]", p.multiply(10))
+    assert get(res, "data[0].patient.ints") == [10, 20, 30]
+    res = update_at(source, "data[*].patient.dicts[-1].num", p.add(1))
+    assert get(res, "data[*].patient.dicts[-1].num") == [3, 5, 7, 8]
+    assert update_at({"totals": {"a": 1, "b": 2}}, "totals.*", p.add(1)) == {
+        "totals": {"a": 2, "b": 3}
+    }
+
+    # Keys that aren't found are skipped
+    assert update_at(source, "data[*].patient.missing", str.upper) == source
+    assert update_at(source, "data[100]", str.upper) == source
+
+
+def test_iter_matches(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    assert list(iter_matches(source, "data[*].patient.id")) == [
+        ("data[0].patient.id", "abc123"),
+        ("data[1].patient.id", "def456"),
+        ("data[2].patient.id", "ghi789"),
+        ("data[3].patient.id", "jkl101112"),
+    ]
+    assert list(iter_matches(source, "data[-1].patient.dicts[*].num")) == [
+        ("data[3].patient.dicts[0].num", 7)
+    ]
+    assert list(iter_matches({"a": {"us.core": 1, "b": None}}, "a.*")) == [
+        ('a."us.core"', 1),
+        ("a.b", None),
+    ]
+    assert list(iter_matches({"a": {"café": 1}}, "a.*")) == [('a."café"', 1)]
+    assert get({"a": {"café": 1}}, 'a."café"') == 1
+    assert list(iter_matches(source, "data[*].patient.ints")) == [
+        ("data[0].patient.ints", [1, 2, 3]),
+        ("data[1].patient.ints", [4, 5, 6]),
+        ("data[2].patient.ints", [7, 8, 9]),
+    ]
+    assert list(iter_matches(source, "data[*].missing")) == []
+
+    # Concrete keys work with `get`, and matches are yielded lazily
+    for k, v in iter_matches(source, "data[*].patient.dicts[*].inner.msg"):
+        assert get(source, k) == v
+    it = iter_matches(source, "data[*].patient.active")
+    assert next(it) == ("data[0].patient.active", True)
@@ -2,8 +2,8 @@ from typing import Any
 
 import pytest
 
-from pydian import Mapper, get
-from pydian.lib.types import DROP, KEEP
+import pydian.mapper
+from pydian import DROP, KEEP, Mapper, get
 
 
 def test_drop(simple_data: dict[str, Any]) -> None:
@@ -30,6 +30,59 @@ def test_drop(simple_data: dict[str, Any]) -> None:
     assert res == {"CASE_parent_keep": {"CASE_curr_keep": {"id": get(source, "data.patient.id")}}}
 
 
+def test_drop_quoted_keys() -> None:
+    source: dict[str, Any] = {}
+
+    def mapping(_: dict[str, Any]) -> dict[str, Any]:
+        return {
+            "CASE_parent_keep": {
+                "us.core.drop": {"a": DROP.THIS_OBJECT, "b": "someValue"},
+                "us.core.keep": {"a.b": "someValue"},
+            },
+            "CASE_list": [{"a.b": "someValue"}, {"a.b": KEEP(None)}],
+        }
+
+    mapper = Mapper(mapping)
+    res = mapper(source)
+    assert res == {
+        "CASE_parent_keep": {"us.core.keep": {"a.b": "someValue"}},
+        "CASE_list": [{"a.b": "someValue"}, {"a.b": None}],
+    }
+
+
+def test_non_str_keys() -> None:
+    source: dict[str, Any] = {}
+
+    def mapping(_: dict[str, Any]) -> dict[str, Any]:
+        return {"counts": {1: "a", 2: {"b": None}}, "b": {"CASE_drop": DROP.THIS_OBJECT}}
+
+    mapper = Mapper(mapping)
+    assert mapper(source) == {"counts": {1: "a"}}
+
+
+def test_drop_keep_sorted_keys(monkeypatch: pytest.MonkeyPatch) -> None:
+    source: dict[str, Any] = {}
+
+    def mapping(_: dict[str, Any]) -> dict[str, Any]:
+        return {
+            "b": {"inner": {"CASE_drop": DROP.PARENT}},
+            "a": [{"CASE_drop": DROP.THIS_OBJECT}, {"CASE_keep": KEEP(None)}],
+            "c": {"z": KEEP(""), "y": KEEP(None), "x": "someValue"},
+        }
+
+    expected = Mapper(mapping)(source)
+    assert expected == {"a": [{"CASE_keep": None}], "c": {"z": "", "y": None, "x": "someValue"}}
+
+    # Return keys in reverse order, so the result can't depend on the order from the set
+    get_keys_containing_class = pydian.mapper.get_keys_containing_class
+    monkeypatch.setattr(
+        pydian.mapper,
+        "get_keys_containing_class",
+        lambda *args: sorted(get_keys_containing_class(*args), reverse=True),
+    )
+    assert Mapper(mapping)(source) == expected
+
+
 def test_drop_out_of_bounds() -> None:
     source: dict[str, Any] = {}
 
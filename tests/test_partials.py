@@ -1,7 +1,10 @@
 from copy import deepcopy
 from typing import Any
 
+import pytest
+
 import pydian.partials as p
+from pydian import get
 
 
 def test_get(simple_data: dict[str, Any]) -> None:
@@ -104,6 +107,29 @@ def test_generic_conditional_wrappers() -> None:
     assert p.not_contained_in(copied_value)(example_key) == (example_key not in value)
 
 
+def test_matches(nested_data: dict[str, Any]) -> None:
+    source = nested_data
+
+    first, second = source["data"][0], source["data"][1]
+    assert p.matches("patient.active")(first) == True
+    assert p.matches("patient.active")(second) == False
+    assert p.matches("patient.active && length(patient.ints[?@ > `2`]) > `0`")(first) == True
+    assert p.matches("patient.active && length(patient.ints[?@ > `5`]) > `0`")(first) == False
+    assert p.matches("patient.dict.char == 'b'")(second) == True
+    assert p.matches("missing.key")(first) == False
+    # JMESPath truthiness, same as a filter in `get`
+    items = [{"n": 0}, {"n": ""}, {"n": []}, {"n": False}, {"n": "a"}]
+    assert [i for i in items if p.matches("n")(i)] == get({"items": items}, "items[?n]")
+    assert [p.matches("n")(i) for i in items] == [True, False, False, False, True]
+
+    # Use as a conditional check in `get`
+    assert (
+        get(source, "data[0]", only_if=p.matches("patient.active"), apply=p.get("patient.id"))
+        == first["patient"]["id"]
+    )
+    assert get(source, "data[1]", only_if=p.matches("patient.active")) is None
+
+
 def test_iterable_wrappers() -> None:
     supported_iterables = ([1, 2, 3, 4, 5], (1, 2, 3, 4, 5))
     for value in supported_iterables:
@@ -119,3 +145,151 @@ def test_stdlib_wrappers() -> None:
     EXAMPLE_LIST = ["a", "b", "c"]
     assert p.map_to_list(str.upper)(EXAMPLE_LIST) == ["A", "B", "C"]
     assert p.filter_to_list(p.equals("a"))(EXAMPLE_LIST) == ["a"]
+
+
+def test_format_date() -> None:
+    ISO_FMT = "%Y-%m-%d"
+    assert p.format_date(ISO_FMT)("2024-01-31") == "2024-01-31"
+    assert p.format_date(ISO_FMT)("20240131") == "2024-01-31"
+    assert p.format_date(ISO_FMT)("01/31/2024") == "2024-01-31"
+    assert p.format_date(ISO_FMT)("31 Jan 2024") == "2024-01-31"
+    assert p.format_date(ISO_FMT)("Jan 31, 2024") == "2024-01-31"
+    assert p.format_date(ISO_FMT)("2024-01-31T12:30:00") == "2024-01-31"
+    assert p.format_date("%m/%d/%Y")("2024-01-31") == "01/31/2024"
+    assert p.format_date(ISO_FMT)("not a date") is None
+
+    # Custom input formats are tried in-order
+    assert p.format_date(ISO_FMT, input_fmts=["%d/%m/%Y"])("01/02/2024") == "2024-02-01"
+    assert p.format_date(ISO_FMT, input_fmts=["%d/%m/%Y"])("2024-02-01") is None
+    # ... and can be any iterable (e.g. a generator), reused across calls
+    from_gen = p.format_date(ISO_FMT, input_fmts=(f for f in ["%d/%m/%Y"]))
+    assert [from_gen("01/02/2024"), from_gen("03/02/2024")] == ["2024-02-01", "2024-02-03"]
+
+    # Optionally return the matched input format
+    assert p.format_date(ISO_FMT, with_input_fmt=True)("01/31/2024") == ("2024-01-31", "%m/%d/%Y")
+    assert p.format_date(ISO_FMT, with_input_fmt=True)("not a date") is None
+
+    # Non-`str` values return `None`, including within `get`
+    assert p.format_date(ISO_FMT)(20240131) is None  # type: ignore
+    assert get({"date": 20240131}, "date", apply=p.format_date(ISO_FMT)) is None
+
+
+def test_group_by(nested_data: dict[str, Any]) -> None:
+    source = nested_data["data"]
+
+    assert p.group_by("patient.active")(source) == {
+        True: [source[0], source[2], source[3]],
+        False: [source[1]],
+    }
+    assert p.group_by("patient.ints[0]")(source) == {
+        1: [source[0]],
+        4: [source[1]],
+        7: [source[2]],
+        None: [source[3]],
+    }
+    assert p.group_by("(patient.active, patient.dict.char)")(source[:2]) == {
+        (True, "a"): [source[0]],
+        (False, "b"): [source[1]],
+    }
+    assert p.group_by("patient.id")([]) == {}
+
+    # Lists are grouped as tuples, other unhashable values raise
+    assert p.group_by("patient.ints")(source) == {
+        (1, 2, 3): [source[0]],
+        (4, 5, 6): [source[1]],
+        (7, 8, 9): [source[2]],
+        None: [source[3]],
+    }
+    with pytest.raises(ValueError):
+        p.group_by("patient.dict")(source)
+
+    # Use with `get`
+    assert get(nested_data, "data[*].patient", apply=p.group_by("active"))[False] == [
+        source[1]["patient"]
+    ]
+
+
+def test_sort_by(nested_data: dict[str, Any]) -> None:
+    source = nested_data["data"]
+    first, second, third, fourth = source
+
+    assert p.sort_by("patient.ints[0]")(source) == [first, second, third, fourth]
+    assert p.sort_by("patient.ints[0]", descending=True)(source) == [third, second, first, fourth]
+    assert p.sort_by("patient.ints[0]", nulls_last=False)(source) == [fourth, first, second, third]
+
+    # Stable on ties, multi-key with per-key direction
+    assert p.sort_by("patient.active")(source) == [second, first, third, fourth]
+    assert p.sort_by(["patient.active", "patient.id"], descending=[True, True])(source) == [
+        fourth,
+        third,
+        first,
+        second,
+    ]
+    with pytest.raises(ValueError):
+        p.sort_by(["patient.active", "patient.id"], descending=[True])
+
+    # Use with `get`
+    assert get(nested_data, "data[0].patient.dicts", apply=p.sort_by("num", descending=True)) == [
+        {"num": 2, "text": "two", "inner": {"msg": "Two!"}},
+        {"num": 1, "text": "one", "inner": {"msg": "One!"}},
+    ]
+
+
+def test_joins() -> None:
+    patients = [
+        {"id": "abc123", "gender": "M"},
+        {"id": "def456", "gender": "F"},
+        {"id": "ghi789", "gender": "U"},
+        {"id": "jkl101112"},
+    ]
+    codes = [
+        {"code": "M", "display": "Male"},
+        {"code": "F", "display": "Female"},
+        {"code": "F", "display": "Female (alt)"},
+    ]
+
+    assert p.left_join(codes, on="gender", second_on="code")(patients) == [
+        {"id": "abc123", "gender": "M", "code": "M", "display": "Male"},
+        {"id": "def456", "gender": "F", "code": "F", "display": "Female"},
+        {"id": "def456", "gender": "F", "code": "F", "display": "Female (alt)"},
+        {"id": "ghi789", "gender": "U"},
+        {"id": "jkl101112"},
+    ]
+    assert p.inner_join(codes, on="gender", second_on="code")(patients) == [
+        {"id": "abc123", "gender": "M", "code": "M", "display": "Male"},
+        {"id": "def456", "gender": "F", "code": "F", "display": "Female"},
+        {"id": "def456", "gender": "F", "code": "F", "display": "Female (alt)"},
+    ]
+    assert p.inner_join([{"id": "abc123", "active": True}], on="id")(patients) == [
+        {"id": "abc123", "gender": "M", "active": True}
+    ]
+    assert p.inner_join(codes, on="missing")(patients) == []
+
+    # Join on list values
+    tagged = [{"tags": ["a", "b"], "id": 1}, {"tags": ["c"], "id": 2}]
+    assert p.inner_join([{"tags": ["a", "b"], "label": "ab"}], on="tags")(tagged) == [
+        {"tags": ["a", "b"], "id": 1, "label": "ab"}
+    ]
+
+
+def test_distinct() -> None:
+    identifiers = [
+        {"system": "mrn", "value": "123"},
+        {"system": "ssn", "value": "456"},
+        {"system": "mrn", "value": "123"},
+        {"system": "mrn", "value": "789"},
+    ]
+
+    assert p.distinct()([3, 1, 3, 2, 1]) == [3, 1, 2]
+    assert p.distinct()([1, True, 1.0, 1, "1"]) == [1, True, 1.0, "1"]
+    assert p.distinct()([[1], {"a": 1}, [1], {"a": 1}, 1]) == [[1], {"a": 1}, 1]
+    assert p.distinct()(identifiers) == identifiers[:2] + identifiers[3:]
+    assert p.distinct("system")(identifiers) == identifiers[:2]
+    assert p.distinct("missing")(identifiers) == identifiers[:1]
+
+    # Canonicalize by chaining with `sort_by`
+    assert get(
+        {"identifiers": identifiers},
+        "identifiers",
+        apply=[p.distinct(), p.sort_by(["system", "value"], descending=[True, False])],
+    ) == [identifiers[1], identifiers[0], identifiers[3]]